@@ -22,13 +22,36 @@ env:
   value: 10000ms
 - name: LINKERD2_PROXY_OUTBOUND_CONNECT_KEEPALIVE
   value: 10000ms
+{{ if .Proxy.DisableInboundDetectionPorts -}}
+- name: LINKERD2_PROXY_INBOUND_PORTS_DISABLE_PROTOCOL_DETECTION
+  value: "{{.Proxy.DisableInboundDetectionPorts}}"
+{{ end -}}
+{{ if .Proxy.DisableOutboundDetectionPorts -}}
+- name: LINKERD2_PROXY_OUTBOUND_PORTS_DISABLE_PROTOCOL_DETECTION
+  value: "{{.Proxy.DisableOutboundDetectionPorts}}"
+{{ end -}}
+{{ if .Proxy.OutboundMaxInFlight -}}
+- name: LINKERD2_PROXY_OUTBOUND_MAX_IN_FLIGHT
+  value: "{{.Proxy.OutboundMaxInFlight}}"
+{{ end -}}
+{{ if .Proxy.OutboundRouterMaxIdleAge -}}
+- name: LINKERD2_PROXY_OUTBOUND_ROUTER_MAX_IDLE_AGE
+  value: "{{.Proxy.OutboundRouterMaxIdleAge}}"
+{{ end -}}
+{{ if .Proxy.MetricsRetainIdle -}}
+- name: LINKERD2_PROXY_METRICS_RETAIN_IDLE
+  value: "{{.Proxy.MetricsRetainIdle}}"
+{{ end -}}
 - name: _pod_ns
   valueFrom:
     fieldRef:
       fieldPath: metadata.namespace
 - name: LINKERD2_PROXY_DESTINATION_CONTEXT
   value: ns:$(_pod_ns)
-{{ if eq .Proxy.Component "linkerd-prometheus" -}}
+{{ if .Proxy.OutboundRouterCapacity -}}
+- name: LINKERD2_PROXY_OUTBOUND_ROUTER_CAPACITY
+  value: "{{.Proxy.OutboundRouterCapacity}}"
+{{ else if eq .Proxy.Component "linkerd-prometheus" -}}
 - name: LINKERD2_PROXY_OUTBOUND_ROUTER_CAPACITY
   value: "10000"
 {{ end -}}
@@ -68,6 +91,14 @@ env:
 - name: LINKERD2_PROXY_TAP_SVC_NAME
   value: linkerd-tap.$(_l5d_ns).serviceaccount.identity.$(_l5d_ns).$(_l5d_trustdomain)
 {{ end -}}
+{{ if .Proxy.Trace -}}
+- name: LINKERD2_PROXY_TRACE_COLLECTOR_SVC_ADDR
+  value: "{{.Proxy.Trace.CollectorSvcAddr}}"
+{{ if not .Proxy.DisableIdentity -}}
+- name: LINKERD2_PROXY_TRACE_COLLECTOR_SVC_NAME
+  value: {{.Proxy.Trace.CollectorSvcAccount}}.serviceaccount.identity.$(_l5d_ns).$(_l5d_trustdomain)
+{{ end -}}
+{{ end -}}
 image: {{.Proxy.Image.Name}}:{{.Proxy.Image.Version}}
 imagePullPolicy: {{.Proxy.Image.PullPolicy}}
 livenessProbe:
@@ -28,6 +28,7 @@ const (
 	injectDisabledDesc = "pods are not annotated to disable injection"
 	unsupportedDesc    = "at least one resource injected"
 	udpDesc            = "pod specs do not include UDP ports"
+	unknownDesc        = "pods do not use unknown or deprecated config annotations"
 )
 
 type resourceTransformerInject struct {
@@ -190,6 +191,7 @@ func (resourceTransformerInject) generateReport(reports []inject.Report, output
 	sidecar := []string{}
 	udp := []string{}
 	injectDisabled := []string{}
+	unknownAnnotations := []string{}
 	warningsPrinted := verbose
 
 	for _, r := range reports {
@@ -216,6 +218,22 @@ func (resourceTransformerInject) generateReport(reports []inject.Report, output
 			injectDisabled = append(injectDisabled, r.ResName())
 			warningsPrinted = true
 		}
+
+		if len(r.UnknownAnnotations) > 0 {
+			unknownAnnotations = append(unknownAnnotations,
+				fmt.Sprintf("%s has unknown annotations that will be ignored: %s", r.ResName(), strings.Join(r.UnknownAnnotations, ", ")))
+			warningsPrinted = true
+		}
+
+		if len(r.DeprecatedAnnotations) > 0 {
+			deprecated := []string{}
+			for _, annotation := range r.DeprecatedAnnotations {
+				deprecated = append(deprecated, fmt.Sprintf("%s (use %s)", annotation, k8s.ProxyConfigDeprecatedAnnotations[annotation]))
+			}
+			unknownAnnotations = append(unknownAnnotations,
+				fmt.Sprintf("%s has deprecated annotations that will be ignored: %s", r.ResName(), strings.Join(deprecated, ", ")))
+			warningsPrinted = true
+		}
 	}
 
 	//
@@ -261,6 +279,14 @@ func (resourceTransformerInject) generateReport(reports []inject.Report, output
 		output.Write([]byte(fmt.Sprintf("%s %s\n", okStatus, udpDesc)))
 	}
 
+	if len(unknownAnnotations) > 0 {
+		for _, unknown := range unknownAnnotations {
+			output.Write([]byte(fmt.Sprintf("%s %s\n", warnStatus, unknown)))
+		}
+	} else if verbose {
+		output.Write([]byte(fmt.Sprintf("%s %s\n", okStatus, unknownDesc)))
+	}
+
 	//
 	// Summary
 	//
@@ -255,6 +255,24 @@ func TestUninjectAndInject(t *testing.T) {
 			injectProxy:      true,
 			testInjectConfig: overrideConfig,
 		},
+		{
+			inputFileName:    "inject_emojivoto_deployment_unknown_annotation.input.yml",
+			goldenFileName:   "inject_emojivoto_deployment_unknown_annotation.golden.yml",
+			reportFileName:   "inject_emojivoto_deployment_unknown_annotation.report",
+			injectProxy:      true,
+			testInjectConfig: defaultConfig,
+		},
+		{
+			inputFileName:    "inject_emojivoto_deployment.input.yml",
+			goldenFileName:   "inject_emojivoto_deployment_trace.golden.yml",
+			reportFileName:   "inject_emojivoto_deployment.report",
+			injectProxy:      true,
+			testInjectConfig: defaultConfig,
+			overrideAnnotations: map[string]string{
+				k8s.ProxyDisableIdentityAnnotation:       "true",
+				k8s.ProxyTraceCollectorSvcAddrAnnotation: "oc-collector.tracing:55678",
+			},
+		},
 		{
 			inputFileName:          "inject_emojivoto_deployment.input.yml",
 			goldenFileName:         "inject_emojivoto_deployment_debug.golden.yml",
@@ -8,6 +8,7 @@ import (
 	"strings"
 	"text/tabwriter"
 
+	"github.com/golang/protobuf/jsonpb"
 	"github.com/linkerd/linkerd2/controller/api/util"
 	pb "github.com/linkerd/linkerd2/controller/gen/public"
 	"github.com/linkerd/linkerd2/pkg/addr"
@@ -82,7 +83,10 @@ func newCmdTap() *cobra.Command {
   linkerd tap pod/web-dlbvj
 
   # tap the test namespace, filter by request to prod namespace
-  linkerd tap ns/test --to ns/prod`,
+  linkerd tap ns/test --to ns/prod
+
+  # tap the web deployment and save the events as JSON, one per line
+  linkerd tap deploy/web -o json > events.json`,
 		Args:      cobra.RangeArgs(1, 2),
 		ValidArgs: util.ValidTargets,
 		RunE: func(cmd *cobra.Command, args []string) error {
@@ -103,13 +107,9 @@ func newCmdTap() *cobra.Command {
 				return err
 			}
 
-			wide := false
 			switch options.output {
-			// TODO: support more output formats?
-			case "":
-				// default output format.
-			case wideOutput:
-				wide = true
+			case "", wideOutput, jsonOutput:
+				// supported output formats.
 			default:
 				return fmt.Errorf("output format \"%s\" not recognized", options.output)
 			}
@@ -119,7 +119,7 @@ func newCmdTap() *cobra.Command {
 				return err
 			}
 
-			return requestTapByResourceFromAPI(os.Stdout, k8sAPI, req, wide)
+			return requestTapByResourceFromAPI(os.Stdout, k8sAPI, req, options.output)
 		},
 	}
 
@@ -140,14 +140,14 @@ func newCmdTap() *cobra.Command {
 	cmd.PersistentFlags().StringVar(&options.path, "path", options.path,
 		"Display requests with paths that start with this prefix")
 	cmd.PersistentFlags().StringVarP(&options.output, "output", "o", options.output,
-		"Output format. One of: wide")
+		"Output format. One of: wide, json")
 
 	return cmd
 }
 
-func requestTapByResourceFromAPI(w io.Writer, k8sAPI *k8s.KubernetesAPI, req *pb.TapByResourceRequest, wide bool) error {
+func requestTapByResourceFromAPI(w io.Writer, k8sAPI *k8s.KubernetesAPI, req *pb.TapByResourceRequest, output string) error {
 	var resource string
-	if wide {
+	if output == wideOutput {
 		resource = req.GetTarget().GetResource().GetType()
 	}
 
@@ -157,6 +157,9 @@ func requestTapByResourceFromAPI(w io.Writer, k8sAPI *k8s.KubernetesAPI, req *pb
 	}
 	defer body.Close()
 
+	if output == jsonOutput {
+		return renderTapJSON(w, reader)
+	}
 	return renderTap(w, reader, resource)
 }
 
@@ -172,6 +175,29 @@ func renderTap(w io.Writer, tapByteStream *bufio.Reader, resource string) error
 }
 
 func writeTapEventsToBuffer(tapByteStream *bufio.Reader, w *tabwriter.Writer, resource string) error {
+	return readTapEvents(tapByteStream, func(event *pb.TapEvent) error {
+		_, err := fmt.Fprintln(w, renderTapEvent(event, resource))
+		return err
+	})
+}
+
+// renderTapJSON writes every tap event as a single line of JSON, so that the
+// stream can be saved to a file and processed later.
+func renderTapJSON(w io.Writer, tapByteStream *bufio.Reader) error {
+	marshaler := jsonpb.Marshaler{}
+	return readTapEvents(tapByteStream, func(event *pb.TapEvent) error {
+		if err := marshaler.Marshal(w, event); err != nil {
+			return err
+		}
+		_, err := fmt.Fprintln(w)
+		return err
+	})
+}
+
+// readTapEvents reads tap events from tapByteStream until it ends, passing
+// each of them to render. Errors reading the stream are printed to stderr and
+// end the stream, while errors returned by render are returned.
+func readTapEvents(tapByteStream *bufio.Reader, render func(*pb.TapEvent) error) error {
 	for {
 		log.Debug("Waiting for data...")
 		event := pb.TapEvent{}
@@ -183,8 +209,7 @@ func writeTapEventsToBuffer(tapByteStream *bufio.Reader, w *tabwriter.Writer, re
 			fmt.Fprintln(os.Stderr, err)
 			break
 		}
-		_, err = fmt.Fprintln(w, renderTapEvent(&event, resource))
-		if err != nil {
+		if err = render(&event); err != nil {
 			return err
 		}
 	}
@@ -5,8 +5,11 @@ import (
 	"io/ioutil"
 	"net/http"
 	"net/http/httptest"
+	"strings"
 	"testing"
 
+	"github.com/golang/protobuf/jsonpb"
+	"github.com/golang/protobuf/proto"
 	"github.com/golang/protobuf/ptypes/duration"
 	"github.com/linkerd/linkerd2/controller/api/util"
 	pb "github.com/linkerd/linkerd2/controller/gen/public"
@@ -18,7 +21,7 @@ import (
 
 const targetName = "pod-666"
 
-func busyTest(t *testing.T, wide bool) {
+func busyTest(t *testing.T, output string) {
 	resourceType := k8s.Pod
 	params := util.TapRequestParams{
 		Resource:  resourceType + "/" + targetName,
@@ -93,13 +96,31 @@ func busyTest(t *testing.T, wide bool) {
 	kubeAPI.Config.Host = ts.URL
 
 	writer := bytes.NewBufferString("")
-	err = requestTapByResourceFromAPI(writer, kubeAPI, req, wide)
+	err = requestTapByResourceFromAPI(writer, kubeAPI, req, output)
 	if err != nil {
 		t.Fatalf("Unexpected error: %v", err)
 	}
 
+	if output == jsonOutput {
+		events := []pb.TapEvent{event1, event2}
+		lines := strings.Split(strings.TrimSuffix(writer.String(), "\n"), "\n")
+		if len(lines) != len(events) {
+			t.Fatalf("Expected %d lines of JSON but got:\n%s", len(events), writer.String())
+		}
+		for i, line := range lines {
+			actual := pb.TapEvent{}
+			if err := jsonpb.UnmarshalString(line, &actual); err != nil {
+				t.Fatalf("Unexpected error: %v", err)
+			}
+			if !proto.Equal(&events[i], &actual) {
+				t.Fatalf("Expected event %v but got %v", &events[i], &actual)
+			}
+		}
+		return
+	}
+
 	var goldenFilePath string
-	if wide {
+	if output == wideOutput {
 		goldenFilePath = "testdata/tap_busy_output_wide.golden"
 	} else {
 		goldenFilePath = "testdata/tap_busy_output.golden"
@@ -118,11 +139,15 @@ func busyTest(t *testing.T, wide bool) {
 
 func TestRequestTapByResourceFromAPI(t *testing.T) {
 	t.Run("Should render busy response if everything went well", func(t *testing.T) {
-		busyTest(t, false)
+		busyTest(t, "")
 	})
 
 	t.Run("Should render wide busy response if everything went well", func(t *testing.T) {
-		busyTest(t, true)
+		busyTest(t, wideOutput)
+	})
+
+	t.Run("Should render JSON busy response if everything went well", func(t *testing.T) {
+		busyTest(t, jsonOutput)
 	})
 
 	t.Run("Should render empty response if no events returned", func(t *testing.T) {
@@ -151,7 +176,7 @@ func TestRequestTapByResourceFromAPI(t *testing.T) {
 		kubeAPI.Config.Host = ts.URL
 
 		writer := bytes.NewBufferString("")
-		err = requestTapByResourceFromAPI(writer, kubeAPI, req, false)
+		err = requestTapByResourceFromAPI(writer, kubeAPI, req, "")
 		if err != nil {
 			t.Fatalf("Unexpected error: %v", err)
 		}
@@ -189,7 +214,7 @@ func TestRequestTapByResourceFromAPI(t *testing.T) {
 		}
 
 		writer := bytes.NewBufferString("")
-		err = requestTapByResourceFromAPI(writer, kubeAPI, req, false)
+		err = requestTapByResourceFromAPI(writer, kubeAPI, req, "")
 		if err == nil {
 			t.Fatalf("Expecting error, got nothing but output [%s]", writer.String())
 		}
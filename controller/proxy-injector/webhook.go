@@ -81,6 +81,14 @@ func Inject(api *k8s.API,
 		return admissionResponse, nil
 	}
 
+	for _, annotation := range report.UnknownAnnotations {
+		log.Warnf("%s: unknown annotation %s will be ignored", report.ResName(), annotation)
+	}
+	for _, annotation := range report.DeprecatedAnnotations {
+		log.Warnf("%s: deprecated annotation %s will be ignored, use %s instead",
+			report.ResName(), annotation, pkgK8s.ProxyConfigDeprecatedAnnotations[annotation])
+	}
+
 	resourceConfig.AppendPodAnnotations(map[string]string{
 		pkgK8s.CreatedByAnnotation: fmt.Sprintf("linkerd/proxy-injector %s", version.Version),
 	})
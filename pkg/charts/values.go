@@ -74,17 +74,24 @@ type (
 
 	// Proxy contains the fields to set the proxy sidecar container
 	Proxy struct {
-		Capabilities           *Capabilities
-		Component              string
-		DisableIdentity        bool
-		DisableTap             bool
-		EnableExternalProfiles bool
-		Image                  *Image
-		LogLevel               string
-		SAMountPath            *SAMountPath
-		Ports                  *Ports
-		Resources              *Resources
-		UID                    int64
+		Capabilities                  *Capabilities
+		Component                     string
+		DisableIdentity               bool
+		DisableInboundDetectionPorts  string
+		DisableOutboundDetectionPorts string
+		DisableTap                    bool
+		EnableExternalProfiles        bool
+		Image                         *Image
+		LogLevel                      string
+		MetricsRetainIdle             string
+		OutboundMaxInFlight           string
+		OutboundRouterCapacity        string
+		OutboundRouterMaxIdleAge      string
+		Trace                         *Trace
+		SAMountPath                   *SAMountPath
+		Ports                         *Ports
+		Resources                     *Resources
+		UID                           int64
 	}
 
 	// ProxyInit contains the fields to set the proxy-init container
@@ -137,6 +144,12 @@ type (
 		ReadOnly  bool
 	}
 
+	// Trace has all the tracing-related Helm variables
+	Trace struct {
+		CollectorSvcAddr    string
+		CollectorSvcAccount string
+	}
+
 	// Resources represents the computational resources setup for a given container
 	Resources struct {
 		CPU    Constraints
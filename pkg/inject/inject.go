@@ -2,10 +2,13 @@ package inject
 
 import (
 	"encoding/json"
+	"fmt"
+	"net"
 	"regexp"
 	"sort"
 	"strconv"
 	"strings"
+	"time"
 
 	"github.com/linkerd/linkerd2/controller/gen/config"
 	"github.com/linkerd/linkerd2/pkg/charts"
@@ -18,6 +21,7 @@ import (
 	k8sResource "k8s.io/apimachinery/pkg/api/resource"
 	metav1 "k8s.io/apimachinery/pkg/apis/meta/v1"
 	"k8s.io/apimachinery/pkg/runtime"
+	"k8s.io/apimachinery/pkg/util/validation"
 	"k8s.io/helm/pkg/chartutil"
 	"sigs.k8s.io/yaml"
 )
@@ -31,6 +35,10 @@ const (
 
 var rTrail = regexp.MustCompile(`\},\s*\]`)
 
+// proxyDisableDetectionPorts are the ports for which the proxy skips protocol
+// detection by default (SMTP and MySQL, where the server speaks first).
+var proxyDisableDetectionPorts = []uint64{25, 3306}
+
 // Origin defines where the input YAML comes from. Refer the ResourceConfig's
 // 'origin' field
 type Origin int
@@ -389,9 +397,15 @@ func (conf *ResourceConfig) complete(template *corev1.PodTemplateSpec) {
 // injectPodSpec adds linkerd sidecars to the provided PodSpec.
 func (conf *ResourceConfig) injectPodSpec(values *patch) {
 	values.Proxy = &charts.Proxy{
-		Component:              conf.pod.labels[k8s.ProxyDeploymentLabel],
-		EnableExternalProfiles: conf.enableExternalProfiles(),
-		DisableTap:             conf.tapDisabled(),
+		Component:                     conf.pod.labels[k8s.ProxyDeploymentLabel],
+		EnableExternalProfiles:        conf.enableExternalProfiles(),
+		DisableTap:                    conf.tapDisabled(),
+		DisableInboundDetectionPorts:  conf.proxyInboundDisableDetectionPorts(),
+		DisableOutboundDetectionPorts: conf.proxyOutboundDisableDetectionPorts(),
+		OutboundMaxInFlight:           conf.proxyOutboundMaxInFlight(),
+		OutboundRouterCapacity:        conf.proxyOutboundRouterCapacity(),
+		OutboundRouterMaxIdleAge:      conf.proxyOutboundRouterMaxIdleAge(),
+		MetricsRetainIdle:             conf.proxyMetricsRetainIdle(),
 		Image: &charts.Image{
 			Name:       conf.proxyImage(),
 			Version:    conf.proxyVersion(),
@@ -406,6 +420,7 @@ func (conf *ResourceConfig) injectPodSpec(values *patch) {
 		},
 		UID:       conf.proxyUID(),
 		Resources: conf.proxyResourceRequirements(),
+		Trace:     conf.trace(),
 	}
 
 	if v := conf.pod.meta.Annotations[k8s.ProxyEnableDebugAnnotation]; v != "" {
@@ -543,6 +558,57 @@ func (conf *ResourceConfig) injectPodAnnotations(values *patch) {
 	}
 }
 
+// configOverrides returns the config.linkerd.io annotations found on the pod
+// or its namespace, sorted and without duplicates.
+func (conf *ResourceConfig) configOverrides() []string {
+	overrides := map[string]string{}
+	for _, annotations := range []map[string]string{conf.pod.meta.Annotations, conf.nsAnnotations} {
+		for annotation := range annotations {
+			if strings.HasPrefix(annotation, k8s.ProxyConfigAnnotationsPrefix+"/") {
+				overrides[annotation] = ""
+			}
+		}
+	}
+	return sortedKeys(overrides)
+}
+
+// unknownOverrides returns the config.linkerd.io annotations found on the pod
+// or its namespace that the injector doesn't know about. Renamed annotations
+// are reported by deprecatedOverrides instead.
+func (conf *ResourceConfig) unknownOverrides() []string {
+	unknown := []string{}
+	for _, annotation := range conf.configOverrides() {
+		if _, ok := k8s.ProxyConfigDeprecatedAnnotations[annotation]; ok {
+			continue
+		}
+		if !isProxyConfigAnnotation(annotation) {
+			unknown = append(unknown, annotation)
+		}
+	}
+	return unknown
+}
+
+// deprecatedOverrides returns the renamed config.linkerd.io annotations found
+// on the pod or its namespace. They're ignored in favor of their new name.
+func (conf *ResourceConfig) deprecatedOverrides() []string {
+	deprecated := []string{}
+	for _, annotation := range conf.configOverrides() {
+		if _, ok := k8s.ProxyConfigDeprecatedAnnotations[annotation]; ok {
+			deprecated = append(deprecated, annotation)
+		}
+	}
+	return deprecated
+}
+
+func isProxyConfigAnnotation(annotation string) bool {
+	for _, known := range k8s.ProxyConfigAnnotations {
+		if annotation == known {
+			return true
+		}
+	}
+	return false
+}
+
 func (conf *ResourceConfig) getOverride(annotation string) string {
 	if override := conf.pod.meta.Annotations[annotation]; override != "" {
 		return override
@@ -783,6 +849,140 @@ func (conf *ResourceConfig) proxyOutboundSkipPorts() string {
 	return strings.Join(ports, ",")
 }
 
+func (conf *ResourceConfig) proxyInboundDisableDetectionPorts() string {
+	return conf.disableDetectionPorts(k8s.ProxyInboundDisableDetectionAnnotation)
+}
+
+func (conf *ResourceConfig) proxyOutboundDisableDetectionPorts() string {
+	return conf.disableDetectionPorts(k8s.ProxyOutboundDisableDetectionAnnotation)
+}
+
+// disableDetectionPorts returns the proxy's default ports followed by the ports
+// found in the given annotation, without duplicates, as the proxy's setting
+// replaces its defaults rather than extending them. Entries that aren't valid
+// ports are skipped (with a warning). An empty string is returned if the
+// annotation isn't set, leaving the proxy's defaults in place.
+func (conf *ResourceConfig) disableDetectionPorts(annotation string) string {
+	override := conf.getOverride(annotation)
+	if override == "" {
+		return ""
+	}
+
+	ports := append([]uint64{}, proxyDisableDetectionPorts...)
+	for _, port := range strings.Split(override, ",") {
+		port = strings.TrimSpace(port)
+		if port == "" {
+			continue
+		}
+		parsed, err := strconv.ParseUint(port, 10, 16)
+		if err != nil || parsed == 0 {
+			log.Warnf("invalid port %q (%s)", port, annotation)
+			continue
+		}
+		ports = append(ports, parsed)
+	}
+
+	seen := map[uint64]struct{}{}
+	list := []string{}
+	for _, port := range ports {
+		if _, ok := seen[port]; ok {
+			continue
+		}
+		seen[port] = struct{}{}
+		list = append(list, strconv.FormatUint(port, 10))
+	}
+	return strings.Join(list, ",")
+}
+
+func (conf *ResourceConfig) proxyOutboundMaxInFlight() string {
+	return conf.countOverride(k8s.ProxyOutboundMaxInFlightAnnotation)
+}
+
+func (conf *ResourceConfig) proxyOutboundRouterCapacity() string {
+	return conf.countOverride(k8s.ProxyOutboundRouterCapacityAnnotation)
+}
+
+// countOverride returns the positive 32-bit integer found in the given
+// annotation, or an empty string if it isn't valid.
+func (conf *ResourceConfig) countOverride(annotation string) string {
+	if override := conf.getOverride(annotation); override != "" {
+		count, err := strconv.ParseUint(strings.TrimSpace(override), 10, 32)
+		if err != nil {
+			log.Warnf("%s (%s)", err, annotation)
+			return ""
+		}
+		if count == 0 {
+			log.Warnf("invalid count %q (%s)", override, annotation)
+			return ""
+		}
+		return strconv.FormatUint(count, 10)
+	}
+	return ""
+}
+
+func (conf *ResourceConfig) proxyOutboundRouterMaxIdleAge() string {
+	return conf.durationOverride(k8s.ProxyOutboundRouterMaxIdleAgeAnnotation)
+}
+
+// durationOverride parses the duration found in the given annotation and
+// returns it in the milliseconds format expected by the proxy. Durations use
+// Go's syntax (e.g. "500ms", "2m") and must be at least 1ms, as shorter ones
+// would be truncated to 0ms.
+func (conf *ResourceConfig) durationOverride(annotation string) string {
+	if override := conf.getOverride(annotation); override != "" {
+		duration, err := time.ParseDuration(strings.TrimSpace(override))
+		if err != nil {
+			log.Warnf("%s (%s)", err, annotation)
+			return ""
+		}
+		if duration < time.Millisecond {
+			log.Warnf("invalid duration %q (%s)", override, annotation)
+			return ""
+		}
+		return strconv.FormatInt(int64(duration/time.Millisecond), 10) + "ms"
+	}
+	return ""
+}
+
+func (conf *ResourceConfig) trace() *charts.Trace {
+	svcAddr := conf.getOverride(k8s.ProxyTraceCollectorSvcAddrAnnotation)
+	if svcAddr == "" {
+		return nil
+	}
+
+	// the collector's namespace is needed to build its identity, so the
+	// address is expected to be in the <service>.<namespace>[...]:<port> form
+	host, _, err := net.SplitHostPort(svcAddr)
+	if err != nil || net.ParseIP(host) != nil {
+		log.Warnf("invalid trace collector address %q (%s)", svcAddr, k8s.ProxyTraceCollectorSvcAddrAnnotation)
+		return nil
+	}
+	hostParts := strings.Split(host, ".")
+	if len(hostParts) < 2 ||
+		len(validation.IsDNS1123Label(hostParts[0])) > 0 ||
+		len(validation.IsDNS1123Label(hostParts[1])) > 0 {
+		log.Warnf("invalid trace collector address %q (%s)", svcAddr, k8s.ProxyTraceCollectorSvcAddrAnnotation)
+		return nil
+	}
+
+	svcAccount := conf.getOverride(k8s.ProxyTraceCollectorSvcAccountAnnotation)
+	if svcAccount == "" {
+		svcAccount = "default"
+	} else if errs := validation.IsDNS1123Subdomain(svcAccount); len(errs) > 0 {
+		log.Warnf("invalid trace collector service account %q (%s)", svcAccount, k8s.ProxyTraceCollectorSvcAccountAnnotation)
+		return nil
+	}
+
+	return &charts.Trace{
+		CollectorSvcAddr:    svcAddr,
+		CollectorSvcAccount: fmt.Sprintf("%s.%s", svcAccount, hostParts[1]),
+	}
+}
+
+func (conf *ResourceConfig) proxyMetricsRetainIdle() string {
+	return conf.durationOverride(k8s.ProxyMetricsRetainIdleAnnotation)
+}
+
 func sortedKeys(m map[string]string) []string {
 	keys := []string{}
 	for k := range m {
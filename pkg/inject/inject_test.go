@@ -15,22 +15,29 @@ import (
 )
 
 type expectedProxyConfigs struct {
-	identityContext      *config.IdentityContext
-	image                string
-	imagePullPolicy      string
-	proxyVersion         string
-	controlPort          int32
-	inboundPort          int32
-	adminPort            int32
-	outboundPort         int32
-	logLevel             string
-	resourceRequirements *charts.Resources
-	proxyUID             int64
-	initImage            string
-	initImagePullPolicy  string
-	initVersion          string
-	inboundSkipPorts     string
-	outboundSkipPorts    string
+	identityContext       *config.IdentityContext
+	image                 string
+	imagePullPolicy       string
+	proxyVersion          string
+	controlPort           int32
+	inboundPort           int32
+	adminPort             int32
+	outboundPort          int32
+	logLevel              string
+	resourceRequirements  *charts.Resources
+	proxyUID              int64
+	initImage             string
+	initImagePullPolicy   string
+	initVersion           string
+	inboundSkipPorts      string
+	outboundSkipPorts     string
+	inboundNoDetectPorts  string
+	outboundNoDetectPorts string
+	outboundMaxInFlight   string
+	routerCapacity        string
+	routerMaxIdleAge      string
+	trace                 *charts.Trace
+	metricsRetainIdle     string
 }
 
 func TestConfigAccessors(t *testing.T) {
@@ -86,24 +93,31 @@ func TestConfigAccessors(t *testing.T) {
 				Template: corev1.PodTemplateSpec{
 					ObjectMeta: metav1.ObjectMeta{
 						Annotations: map[string]string{
-							k8s.ProxyDisableIdentityAnnotation:        "true",
-							k8s.ProxyImageAnnotation:                  "gcr.io/linkerd-io/proxy",
-							k8s.ProxyImagePullPolicyAnnotation:        "Always",
-							k8s.ProxyInitImageAnnotation:              "gcr.io/linkerd-io/proxy-init",
-							k8s.ProxyControlPortAnnotation:            "4000",
-							k8s.ProxyInboundPortAnnotation:            "5000",
-							k8s.ProxyAdminPortAnnotation:              "5001",
-							k8s.ProxyOutboundPortAnnotation:           "5002",
-							k8s.ProxyIgnoreInboundPortsAnnotation:     "4222,6222",
-							k8s.ProxyIgnoreOutboundPortsAnnotation:    "8079,8080",
-							k8s.ProxyCPURequestAnnotation:             "0.15",
-							k8s.ProxyMemoryRequestAnnotation:          "120",
-							k8s.ProxyCPULimitAnnotation:               "1.5",
-							k8s.ProxyMemoryLimitAnnotation:            "256",
-							k8s.ProxyUIDAnnotation:                    "8500",
-							k8s.ProxyLogLevelAnnotation:               "debug,linkerd2_proxy=debug",
-							k8s.ProxyEnableExternalProfilesAnnotation: "false",
-							k8s.ProxyVersionOverrideAnnotation:        proxyVersionOverride},
+							k8s.ProxyDisableIdentityAnnotation:          "true",
+							k8s.ProxyImageAnnotation:                    "gcr.io/linkerd-io/proxy",
+							k8s.ProxyImagePullPolicyAnnotation:          "Always",
+							k8s.ProxyInitImageAnnotation:                "gcr.io/linkerd-io/proxy-init",
+							k8s.ProxyControlPortAnnotation:              "4000",
+							k8s.ProxyInboundPortAnnotation:              "5000",
+							k8s.ProxyAdminPortAnnotation:                "5001",
+							k8s.ProxyOutboundPortAnnotation:             "5002",
+							k8s.ProxyIgnoreInboundPortsAnnotation:       "4222,6222",
+							k8s.ProxyIgnoreOutboundPortsAnnotation:      "8079,8080",
+							k8s.ProxyCPURequestAnnotation:               "0.15",
+							k8s.ProxyMemoryRequestAnnotation:            "120",
+							k8s.ProxyCPULimitAnnotation:                 "1.5",
+							k8s.ProxyMemoryLimitAnnotation:              "256",
+							k8s.ProxyUIDAnnotation:                      "8500",
+							k8s.ProxyLogLevelAnnotation:                 "debug,linkerd2_proxy=debug",
+							k8s.ProxyEnableExternalProfilesAnnotation:   "false",
+							k8s.ProxyInboundDisableDetectionAnnotation:  "3306, 25,bogus",
+							k8s.ProxyOutboundDisableDetectionAnnotation: "27017",
+							k8s.ProxyOutboundMaxInFlightAnnotation:      "1000",
+							k8s.ProxyOutboundRouterCapacityAnnotation:   "500",
+							k8s.ProxyOutboundRouterMaxIdleAgeAnnotation: "5m",
+							k8s.ProxyTraceCollectorSvcAddrAnnotation:    "oc-collector.tracing:55678",
+							k8s.ProxyMetricsRetainIdleAnnotation:        "10m",
+							k8s.ProxyVersionOverrideAnnotation:          proxyVersionOverride},
 					},
 					Spec: corev1.PodSpec{},
 				},
@@ -127,12 +141,19 @@ func TestConfigAccessors(t *testing.T) {
 						Request: "120",
 					},
 				},
-				proxyUID:            int64(8500),
-				initImage:           "gcr.io/linkerd-io/proxy-init",
-				initImagePullPolicy: "Always",
-				initVersion:         version.ProxyInitVersion,
-				inboundSkipPorts:    "4222,6222",
-				outboundSkipPorts:   "8079,8080",
+				proxyUID:              int64(8500),
+				initImage:             "gcr.io/linkerd-io/proxy-init",
+				initImagePullPolicy:   "Always",
+				initVersion:           version.ProxyInitVersion,
+				inboundSkipPorts:      "4222,6222",
+				outboundSkipPorts:     "8079,8080",
+				inboundNoDetectPorts:  "25,3306",
+				outboundNoDetectPorts: "25,3306,27017",
+				outboundMaxInFlight:   "1000",
+				routerCapacity:        "500",
+				routerMaxIdleAge:      "300000ms",
+				trace:                 &charts.Trace{CollectorSvcAddr: "oc-collector.tracing:55678", CollectorSvcAccount: "default.tracing"},
+				metricsRetainIdle:     "600000ms",
 			},
 		},
 		{id: "use defaults",
@@ -172,24 +193,31 @@ func TestConfigAccessors(t *testing.T) {
 		},
 		{id: "use namespace overrides",
 			nsAnnotations: map[string]string{
-				k8s.ProxyDisableIdentityAnnotation:        "true",
-				k8s.ProxyImageAnnotation:                  "gcr.io/linkerd-io/proxy",
-				k8s.ProxyImagePullPolicyAnnotation:        "Always",
-				k8s.ProxyInitImageAnnotation:              "gcr.io/linkerd-io/proxy-init",
-				k8s.ProxyControlPortAnnotation:            "4000",
-				k8s.ProxyInboundPortAnnotation:            "5000",
-				k8s.ProxyAdminPortAnnotation:              "5001",
-				k8s.ProxyOutboundPortAnnotation:           "5002",
-				k8s.ProxyIgnoreInboundPortsAnnotation:     "4222,6222",
-				k8s.ProxyIgnoreOutboundPortsAnnotation:    "8079,8080",
-				k8s.ProxyCPURequestAnnotation:             "0.15",
-				k8s.ProxyMemoryRequestAnnotation:          "120",
-				k8s.ProxyCPULimitAnnotation:               "1.5",
-				k8s.ProxyMemoryLimitAnnotation:            "256",
-				k8s.ProxyUIDAnnotation:                    "8500",
-				k8s.ProxyLogLevelAnnotation:               "debug,linkerd2_proxy=debug",
-				k8s.ProxyEnableExternalProfilesAnnotation: "false",
-				k8s.ProxyVersionOverrideAnnotation:        proxyVersionOverride},
+				k8s.ProxyDisableIdentityAnnotation:          "true",
+				k8s.ProxyImageAnnotation:                    "gcr.io/linkerd-io/proxy",
+				k8s.ProxyImagePullPolicyAnnotation:          "Always",
+				k8s.ProxyInitImageAnnotation:                "gcr.io/linkerd-io/proxy-init",
+				k8s.ProxyControlPortAnnotation:              "4000",
+				k8s.ProxyInboundPortAnnotation:              "5000",
+				k8s.ProxyAdminPortAnnotation:                "5001",
+				k8s.ProxyOutboundPortAnnotation:             "5002",
+				k8s.ProxyIgnoreInboundPortsAnnotation:       "4222,6222",
+				k8s.ProxyIgnoreOutboundPortsAnnotation:      "8079,8080",
+				k8s.ProxyCPURequestAnnotation:               "0.15",
+				k8s.ProxyMemoryRequestAnnotation:            "120",
+				k8s.ProxyCPULimitAnnotation:                 "1.5",
+				k8s.ProxyMemoryLimitAnnotation:              "256",
+				k8s.ProxyUIDAnnotation:                      "8500",
+				k8s.ProxyLogLevelAnnotation:                 "debug,linkerd2_proxy=debug",
+				k8s.ProxyEnableExternalProfilesAnnotation:   "false",
+				k8s.ProxyInboundDisableDetectionAnnotation:  "3306, 25,bogus",
+				k8s.ProxyOutboundDisableDetectionAnnotation: "27017",
+				k8s.ProxyOutboundMaxInFlightAnnotation:      "1000",
+				k8s.ProxyOutboundRouterCapacityAnnotation:   "500",
+				k8s.ProxyOutboundRouterMaxIdleAgeAnnotation: "5m",
+				k8s.ProxyTraceCollectorSvcAddrAnnotation:    "oc-collector.tracing:55678",
+				k8s.ProxyMetricsRetainIdleAnnotation:        "10m",
+				k8s.ProxyVersionOverrideAnnotation:          proxyVersionOverride},
 			spec: appsv1.DeploymentSpec{
 				Template: corev1.PodTemplateSpec{
 					Spec: corev1.PodSpec{},
@@ -214,12 +242,19 @@ func TestConfigAccessors(t *testing.T) {
 						Request: "120",
 					},
 				},
-				proxyUID:            int64(8500),
-				initImage:           "gcr.io/linkerd-io/proxy-init",
-				initImagePullPolicy: "Always",
-				initVersion:         version.ProxyInitVersion,
-				inboundSkipPorts:    "4222,6222",
-				outboundSkipPorts:   "8079,8080",
+				proxyUID:              int64(8500),
+				initImage:             "gcr.io/linkerd-io/proxy-init",
+				initImagePullPolicy:   "Always",
+				initVersion:           version.ProxyInitVersion,
+				inboundSkipPorts:      "4222,6222",
+				outboundSkipPorts:     "8079,8080",
+				inboundNoDetectPorts:  "25,3306",
+				outboundNoDetectPorts: "25,3306,27017",
+				outboundMaxInFlight:   "1000",
+				routerCapacity:        "500",
+				routerMaxIdleAge:      "300000ms",
+				trace:                 &charts.Trace{CollectorSvcAddr: "oc-collector.tracing:55678", CollectorSvcAccount: "default.tracing"},
+				metricsRetainIdle:     "600000ms",
 			},
 		},
 	}
@@ -348,6 +383,304 @@ func TestConfigAccessors(t *testing.T) {
 					t.Errorf("Expected: %v Actual: %v", expected, actual)
 				}
 			})
+
+			t.Run("proxyInboundDisableDetectionPorts", func(t *testing.T) {
+				expected := testCase.expected.inboundNoDetectPorts
+				if actual := resourceConfig.proxyInboundDisableDetectionPorts(); expected != actual {
+					t.Errorf("Expected: %v Actual: %v", expected, actual)
+				}
+			})
+
+			t.Run("proxyOutboundDisableDetectionPorts", func(t *testing.T) {
+				expected := testCase.expected.outboundNoDetectPorts
+				if actual := resourceConfig.proxyOutboundDisableDetectionPorts(); expected != actual {
+					t.Errorf("Expected: %v Actual: %v", expected, actual)
+				}
+			})
+
+			t.Run("proxyOutboundMaxInFlight", func(t *testing.T) {
+				expected := testCase.expected.outboundMaxInFlight
+				if actual := resourceConfig.proxyOutboundMaxInFlight(); expected != actual {
+					t.Errorf("Expected: %v Actual: %v", expected, actual)
+				}
+			})
+
+			t.Run("proxyOutboundRouterCapacity", func(t *testing.T) {
+				expected := testCase.expected.routerCapacity
+				if actual := resourceConfig.proxyOutboundRouterCapacity(); expected != actual {
+					t.Errorf("Expected: %v Actual: %v", expected, actual)
+				}
+			})
+
+			t.Run("proxyOutboundRouterMaxIdleAge", func(t *testing.T) {
+				expected := testCase.expected.routerMaxIdleAge
+				if actual := resourceConfig.proxyOutboundRouterMaxIdleAge(); expected != actual {
+					t.Errorf("Expected: %v Actual: %v", expected, actual)
+				}
+			})
+
+			t.Run("trace", func(t *testing.T) {
+				expected := testCase.expected.trace
+				if actual := resourceConfig.trace(); !reflect.DeepEqual(expected, actual) {
+					t.Errorf("Expected: %v Actual: %v", expected, actual)
+				}
+			})
+
+			t.Run("proxyMetricsRetainIdle", func(t *testing.T) {
+				expected := testCase.expected.metricsRetainIdle
+				if actual := resourceConfig.proxyMetricsRetainIdle(); expected != actual {
+					t.Errorf("Expected: %v Actual: %v", expected, actual)
+				}
+			})
+		})
+	}
+}
+
+func TestTrace(t *testing.T) {
+	testCases := []struct {
+		id          string
+		annotations map[string]string
+		expected    *charts.Trace
+	}{
+		{
+			id:          "no collector",
+			annotations: map[string]string{},
+			expected:    nil,
+		},
+		{
+			id: "default service account",
+			annotations: map[string]string{
+				k8s.ProxyTraceCollectorSvcAddrAnnotation: "oc-collector.tracing:55678",
+			},
+			expected: &charts.Trace{CollectorSvcAddr: "oc-collector.tracing:55678", CollectorSvcAccount: "default.tracing"},
+		},
+		{
+			id: "service account override",
+			annotations: map[string]string{
+				k8s.ProxyTraceCollectorSvcAddrAnnotation:    "oc-collector.tracing.svc.cluster.local:55678",
+				k8s.ProxyTraceCollectorSvcAccountAnnotation: "oc-collector",
+			},
+			expected: &charts.Trace{CollectorSvcAddr: "oc-collector.tracing.svc.cluster.local:55678", CollectorSvcAccount: "oc-collector.tracing"},
+		},
+		{
+			id: "invalid service account",
+			annotations: map[string]string{
+				k8s.ProxyTraceCollectorSvcAddrAnnotation:    "oc-collector.tracing:55678",
+				k8s.ProxyTraceCollectorSvcAccountAnnotation: "oc collector",
+			},
+			expected: nil,
+		},
+		{
+			id: "missing port",
+			annotations: map[string]string{
+				k8s.ProxyTraceCollectorSvcAddrAnnotation: "oc-collector.tracing",
+			},
+			expected: nil,
+		},
+		{
+			id: "missing namespace",
+			annotations: map[string]string{
+				k8s.ProxyTraceCollectorSvcAddrAnnotation: "oc-collector:55678",
+			},
+			expected: nil,
+		},
+		{
+			id: "ip address",
+			annotations: map[string]string{
+				k8s.ProxyTraceCollectorSvcAddrAnnotation: "10.0.0.1:55678",
+			},
+			expected: nil,
+		},
+		{
+			id: "invalid namespace",
+			annotations: map[string]string{
+				k8s.ProxyTraceCollectorSvcAddrAnnotation: "oc-collector.tra_cing:55678",
+			},
+			expected: nil,
+		},
+	}
+
+	for _, tc := range testCases {
+		testCase := tc
+		t.Run(testCase.id, func(t *testing.T) {
+			resourceConfig := NewResourceConfig(&config.All{}, OriginUnknown)
+			resourceConfig.pod.meta.Annotations = testCase.annotations
+
+			if actual := resourceConfig.trace(); !reflect.DeepEqual(testCase.expected, actual) {
+				t.Errorf("Expected: %+v Actual: %+v", testCase.expected, actual)
+			}
+		})
+	}
+}
+
+func TestDisableDetectionPorts(t *testing.T) {
+	testCases := []struct {
+		override string
+		expected string
+	}{
+		{override: "", expected: ""},
+		{override: "8080", expected: "25,3306,8080"},
+		{override: "3306, 8080,8080", expected: "25,3306,8080"},
+		{override: "0025,bogus,65536,0", expected: "25,3306"},
+	}
+
+	for _, tc := range testCases {
+		testCase := tc
+		t.Run(testCase.override, func(t *testing.T) {
+			resourceConfig := NewResourceConfig(&config.All{}, OriginUnknown)
+			resourceConfig.pod.meta.Annotations = map[string]string{
+				k8s.ProxyInboundDisableDetectionAnnotation: testCase.override,
+			}
+
+			if actual := resourceConfig.proxyInboundDisableDetectionPorts(); testCase.expected != actual {
+				t.Errorf("Expected: %v Actual: %v", testCase.expected, actual)
+			}
+		})
+	}
+}
+
+func TestDurationOverride(t *testing.T) {
+	testCases := []struct {
+		override string
+		expected string
+	}{
+		{override: "", expected: ""},
+		{override: "1m", expected: "60000ms"},
+		{override: "1500ms", expected: "1500ms"},
+		{override: "1ms", expected: "1ms"},
+		{override: " 5m ", expected: "300000ms"},
+		{override: "500us", expected: ""},
+		{override: "0s", expected: ""},
+		{override: "-1s", expected: ""},
+		{override: "10", expected: ""},
+		{override: "bogus", expected: ""},
+	}
+
+	for _, tc := range testCases {
+		testCase := tc
+		t.Run(testCase.override, func(t *testing.T) {
+			resourceConfig := NewResourceConfig(&config.All{}, OriginUnknown)
+			resourceConfig.pod.meta.Annotations = map[string]string{
+				k8s.ProxyOutboundRouterMaxIdleAgeAnnotation: testCase.override,
+			}
+
+			if actual := resourceConfig.proxyOutboundRouterMaxIdleAge(); testCase.expected != actual {
+				t.Errorf("Expected: %v Actual: %v", testCase.expected, actual)
+			}
+		})
+	}
+}
+
+func TestCountOverride(t *testing.T) {
+	testCases := []struct {
+		override string
+		expected string
+	}{
+		{override: "", expected: ""},
+		{override: "10", expected: "10"},
+		{override: " 20 ", expected: "20"},
+		{override: "0", expected: ""},
+		{override: "-1", expected: ""},
+		{override: "4294967296", expected: ""},
+		{override: "1.5", expected: ""},
+		{override: "abc", expected: ""},
+	}
+
+	for _, tc := range testCases {
+		testCase := tc
+		t.Run(testCase.override, func(t *testing.T) {
+			resourceConfig := NewResourceConfig(&config.All{}, OriginUnknown)
+			resourceConfig.pod.meta.Annotations = map[string]string{
+				k8s.ProxyOutboundMaxInFlightAnnotation: testCase.override,
+			}
+
+			if actual := resourceConfig.proxyOutboundMaxInFlight(); testCase.expected != actual {
+				t.Errorf("Expected: %v Actual: %v", testCase.expected, actual)
+			}
+		})
+	}
+}
+
+func TestUnknownOverrides(t *testing.T) {
+	known := map[string]string{}
+	for _, annotation := range k8s.ProxyConfigAnnotations {
+		known[annotation] = "value"
+	}
+
+	testCases := []struct {
+		id                 string
+		podAnnotations     map[string]string
+		nsAnnotations      map[string]string
+		expectedUnknown    []string
+		expectedDeprecated []string
+	}{
+		{
+			id:                 "known annotations",
+			podAnnotations:     known,
+			nsAnnotations:      known,
+			expectedUnknown:    []string{},
+			expectedDeprecated: []string{},
+		},
+		{
+			id: "unknown pod annotation",
+			podAnnotations: map[string]string{
+				k8s.ProxyCPULimitAnnotation:               "1",
+				k8s.ProxyConfigAnnotationsPrefix + "/cpu": "1",
+				k8s.ProxyInjectAnnotation:                 k8s.ProxyInjectEnabled,
+				"config.linkerd.io.example/cpu":           "1",
+			},
+			expectedUnknown:    []string{"config.linkerd.io/cpu"},
+			expectedDeprecated: []string{},
+		},
+		{
+			id: "unknown namespace annotation",
+			podAnnotations: map[string]string{
+				k8s.ProxyCPULimitAnnotation: "1",
+			},
+			nsAnnotations: map[string]string{
+				k8s.ProxyConfigAnnotationsPrefix + "/proxy-logging": "debug",
+				k8s.ProxyLogLevelAnnotation:                         "debug",
+			},
+			expectedUnknown:    []string{"config.linkerd.io/proxy-logging"},
+			expectedDeprecated: []string{},
+		},
+		{
+			id: "unknown annotation on both",
+			podAnnotations: map[string]string{
+				k8s.ProxyConfigAnnotationsPrefix + "/cpu": "1",
+			},
+			nsAnnotations: map[string]string{
+				k8s.ProxyConfigAnnotationsPrefix + "/cpu":    "2",
+				k8s.ProxyConfigAnnotationsPrefix + "/memory": "64Mi",
+			},
+			expectedUnknown:    []string{"config.linkerd.io/cpu", "config.linkerd.io/memory"},
+			expectedDeprecated: []string{},
+		},
+		{
+			id: "deprecated annotation",
+			podAnnotations: map[string]string{
+				k8s.ProxyConfigAnnotationsPrefix + "/debug": "true",
+			},
+			nsAnnotations: map[string]string{
+				k8s.ProxyConfigAnnotationsPrefix + "/debug": "true",
+			},
+			expectedUnknown:    []string{},
+			expectedDeprecated: []string{"config.linkerd.io/debug"},
+		},
+	}
+
+	for _, tc := range testCases {
+		testCase := tc
+		t.Run(testCase.id, func(t *testing.T) {
+			resourceConfig := NewResourceConfig(&config.All{}, OriginUnknown).WithNsAnnotations(testCase.nsAnnotations)
+			resourceConfig.pod.meta.Annotations = testCase.podAnnotations
+
+			if actual := resourceConfig.unknownOverrides(); !reflect.DeepEqual(testCase.expectedUnknown, actual) {
+				t.Errorf("Expected: %v Actual: %v", testCase.expectedUnknown, actual)
+			}
+			if actual := resourceConfig.deprecatedOverrides(); !reflect.DeepEqual(testCase.expectedDeprecated, actual) {
+				t.Errorf("Expected: %v Actual: %v", testCase.expectedDeprecated, actual)
+			}
 		})
 	}
 }
@@ -12,14 +12,16 @@ import (
 // Report contains the Kind and Name for a given workload along with booleans
 // describing the result of the injection transformation
 type Report struct {
-	Kind                 string
-	Name                 string
-	HostNetwork          bool
-	Sidecar              bool
-	UDP                  bool // true if any port in any container has `protocol: UDP`
-	UnsupportedResource  bool
-	InjectDisabled       bool
-	InjectDisabledReason string
+	Kind                  string
+	Name                  string
+	HostNetwork           bool
+	Sidecar               bool
+	UDP                   bool // true if any port in any container has `protocol: UDP`
+	UnsupportedResource   bool
+	InjectDisabled        bool
+	InjectDisabledReason  string
+	UnknownAnnotations    []string // config.linkerd.io annotations the injector doesn't know about
+	DeprecatedAnnotations []string // renamed config.linkerd.io annotations, see k8s.ProxyConfigDeprecatedAnnotations
 
 	// Uninjected consists of two boolean flags to indicate if a proxy and
 	// proxy-init containers have been uninjected in this report
@@ -55,6 +57,8 @@ func newReport(conf *ResourceConfig) *Report {
 		report.HostNetwork = conf.pod.spec.HostNetwork
 		report.Sidecar = healthcheck.HasExistingSidecars(conf.pod.spec)
 		report.UDP = checkUDPPorts(conf.pod.spec)
+		report.UnknownAnnotations = conf.unknownOverrides()
+		report.DeprecatedAnnotations = conf.deprecatedOverrides()
 	} else {
 		report.UnsupportedResource = true
 	}
@@ -169,6 +169,49 @@ const (
 	// injected.
 	ProxyEnableDebugAnnotation = ProxyConfigAnnotationsPrefix + "/enable-debug-sidecar"
 
+	// ProxyInboundDisableDetectionAnnotation can be used to configure a
+	// comma-separated list of inbound ports for which the proxy skips protocol
+	// detection and forwards connections as raw TCP. The proxy's own defaults
+	// (25 and 3306) are replaced when the setting is used, so the injector
+	// always adds them to the list.
+	ProxyInboundDisableDetectionAnnotation = ProxyConfigAnnotationsPrefix + "/inbound-ports-disable-protocol-detection"
+
+	// ProxyOutboundDisableDetectionAnnotation can be used to configure a
+	// comma-separated list of outbound ports for which the proxy skips protocol
+	// detection and forwards connections as raw TCP. The proxy's own defaults
+	// (25 and 3306) are replaced when the setting is used, so the injector
+	// always adds them to the list.
+	ProxyOutboundDisableDetectionAnnotation = ProxyConfigAnnotationsPrefix + "/outbound-ports-disable-protocol-detection"
+
+	// ProxyOutboundMaxInFlightAnnotation can be used to limit the number of
+	// in-flight outbound requests per destination service. Requests beyond the
+	// limit are queued and eventually shed with a 503.
+	ProxyOutboundMaxInFlightAnnotation = ProxyConfigAnnotationsPrefix + "/proxy-outbound-max-in-flight"
+
+	// ProxyOutboundRouterCapacityAnnotation can be used to configure the
+	// maximum number of authorities the proxy's outbound router caches at once.
+	ProxyOutboundRouterCapacityAnnotation = ProxyConfigAnnotationsPrefix + "/proxy-outbound-router-capacity"
+
+	// ProxyOutboundRouterMaxIdleAgeAnnotation can be used to configure how
+	// long an authority cached by the proxy's outbound router may go without
+	// traffic before its balancer and resolution are torn down.
+	ProxyOutboundRouterMaxIdleAgeAnnotation = ProxyConfigAnnotationsPrefix + "/proxy-outbound-router-max-idle-age"
+
+	// ProxyTraceCollectorSvcAddrAnnotation can be used to enable tracing on a
+	// proxy. It takes the collector service name (e.g. oc-collector.tracing:55678)
+	// as its value.
+	ProxyTraceCollectorSvcAddrAnnotation = ProxyConfigAnnotationsPrefix + "/trace-collector"
+
+	// ProxyTraceCollectorSvcAccountAnnotation is used to specify the service
+	// account associated with the trace collector. It is used to create the
+	// collector's identity. Defaults to "default".
+	ProxyTraceCollectorSvcAccountAnnotation = ProxyConfigAnnotationsPrefix + "/trace-collector-service-account"
+
+	// ProxyMetricsRetainIdleAnnotation can be used to configure how long the
+	// proxy retains metrics for a label set (e.g. a destination or client) that
+	// has seen no traffic before dropping it from its scrape output.
+	ProxyMetricsRetainIdleAnnotation = ProxyConfigAnnotationsPrefix + "/proxy-metrics-retain-idle"
+
 	// IdentityModeDefault is assigned to IdentityModeAnnotation to
 	// use the control plane's default identity scheme.
 	IdentityModeDefault = "default"
@@ -267,6 +310,47 @@ const (
 	IdentityServiceAccountTokenPath = "/var/run/secrets/kubernetes.io/serviceaccount/token"
 )
 
+// ProxyConfigAnnotations lists the config.linkerd.io annotations understood by
+// the proxy injector. Annotations added to the block above must be listed here
+// too, or injecting workloads that use them reports them as unknown.
+var ProxyConfigAnnotations = []string{
+	ProxyImageAnnotation,
+	ProxyImagePullPolicyAnnotation,
+	ProxyInitImageAnnotation,
+	ProxyInitImageVersionAnnotation,
+	ProxyControlPortAnnotation,
+	ProxyIgnoreInboundPortsAnnotation,
+	ProxyIgnoreOutboundPortsAnnotation,
+	ProxyInboundPortAnnotation,
+	ProxyAdminPortAnnotation,
+	ProxyOutboundPortAnnotation,
+	ProxyCPURequestAnnotation,
+	ProxyMemoryRequestAnnotation,
+	ProxyCPULimitAnnotation,
+	ProxyMemoryLimitAnnotation,
+	ProxyUIDAnnotation,
+	ProxyLogLevelAnnotation,
+	ProxyEnableExternalProfilesAnnotation,
+	ProxyVersionOverrideAnnotation,
+	ProxyDisableIdentityAnnotation,
+	ProxyDisableTapAnnotation,
+	ProxyEnableDebugAnnotation,
+	ProxyInboundDisableDetectionAnnotation,
+	ProxyOutboundDisableDetectionAnnotation,
+	ProxyOutboundMaxInFlightAnnotation,
+	ProxyOutboundRouterCapacityAnnotation,
+	ProxyOutboundRouterMaxIdleAgeAnnotation,
+	ProxyTraceCollectorSvcAddrAnnotation,
+	ProxyTraceCollectorSvcAccountAnnotation,
+	ProxyMetricsRetainIdleAnnotation,
+}
+
+// ProxyConfigDeprecatedAnnotations maps the config.linkerd.io annotations that
+// have been renamed to the annotation replacing them.
+var ProxyConfigDeprecatedAnnotations = map[string]string{
+	ProxyConfigAnnotationsPrefix + "/debug": ProxyEnableDebugAnnotation,
+}
+
 // CreatedByAnnotationValue returns the value associated with
 // CreatedByAnnotation.
 func CreatedByAnnotationValue() string {